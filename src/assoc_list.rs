@@ -0,0 +1,264 @@
+//! 关联链表（alist）实现
+//!
+//! 这个模块提供了一个基于单向链表的小型映射，每个节点保存一个键值对，
+//! 查找时从头到尾线性比较键。只要求 `K: PartialEq`，不需要哈希或排序。
+//!
+//! # 设计特点
+//!
+//! - **适用场景**：条目很少（如少于 16 个）时，内存占用和速度都优于 `HashMap`
+//! - **头插**：新键插入到表头，O(1)
+//! - **移至表头**：可选的自组织策略，`get_mtf`/`get_mut`/`insert` 命中的节点被移动到表头，
+//!   使常用键越来越靠前；只读的 `get` 不改变顺序
+//!
+//! # 内存布局
+//! ```text
+//! head
+//!  |
+//!  v
+//! +--------+    +--------+    +--------+
+//! | k1: v1 |--->| k2: v2 |--->| k3: v3 |---> None
+//! +--------+    +--------+    +--------+
+//! ```
+
+use std::fmt::Debug;
+
+struct Node<K, V> {
+    key: K,
+    val: V,
+    next: Option<Box<Node<K, V>>>,
+}
+
+pub struct AssocList<K, V> {
+    head: Option<Box<Node<K, V>>>,
+    len: usize,
+    // 命中时是否移至表头
+    move_to_front: bool,
+}
+
+impl<K, V> AssocList<K, V> {
+    pub fn new() -> Self {
+        Self { head: None, len: 0, move_to_front: false }
+    }
+
+    /// 启用移至表头策略的空表
+    ///
+    /// 策略只作用于 [`get_mtf`](Self::get_mtf)、[`get_mut`](Self::get_mut) 和
+    /// [`insert`](Self::insert)；`&self` 的 [`get`](Self::get) 不会调整顺序
+    pub fn with_move_to_front() -> Self {
+        Self { move_to_front: true, ..Self::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 从表头开始依次返回键值对
+    pub fn iter(&self) -> AssocIter<'_, K, V> {
+        AssocIter { next: self.head.as_deref() }
+    }
+
+    fn push_front(&mut self, mut node: Box<Node<K, V>>) {
+        node.next = self.head.take();
+        self.head = Some(node);
+    }
+}
+
+impl<K: PartialEq, V> AssocList<K, V> {
+    /// 查找键，不改变表的顺序
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// 查找键，启用移至表头时命中的节点会被移到表头，读多的场景应使用此方法
+    pub fn get_mtf(&mut self, key: &K) -> Option<&V> {
+        self.get_mut(key).map(|val| &*val)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 查找键并返回可变引用，启用移至表头时命中的节点会被移到表头
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        if self.move_to_front {
+            let node = self.unlink(key)?;
+            self.push_front(node);
+            return self.head.as_mut().map(|node| &mut node.val);
+        }
+
+        let mut cur = self.head.as_deref_mut();
+        while let Some(node) = cur {
+            if node.key == *key {
+                return Some(&mut node.val);
+            }
+            cur = node.next.as_deref_mut();
+        }
+        None
+    }
+
+    /// 插入键值对，键已存在时替换并返回旧值
+    pub fn insert(&mut self, key: K, val: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(std::mem::replace(old, val));
+        }
+
+        self.push_front(Box::new(Node { key, val, next: None }));
+        self.len += 1;
+        None
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.unlink(key).map(|node| {
+            self.len -= 1;
+            node.val
+        })
+    }
+
+    /// 摘下键对应的节点，不修改 len
+    fn unlink(&mut self, key: &K) -> Option<Box<Node<K, V>>> {
+        let mut cur = &mut self.head;
+        while cur.as_ref().is_some_and(|node| node.key != *key) {
+            cur = &mut cur.as_mut().unwrap().next;
+        }
+
+        // prev.next -> node.next
+        let mut node = cur.take()?;
+        *cur = node.next.take();
+        Some(node)
+    }
+}
+
+impl<K, V> Default for AssocList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PartialEq, V> FromIterator<(K, V)> for AssocList<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for (key, val) in iter {
+            list.insert(key, val);
+        }
+        list
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for AssocList<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// 从表头到表尾的借用迭代器
+pub struct AssocIter<'a, K, V> {
+    next: Option<&'a Node<K, V>>,
+}
+
+impl<'a, K, V> Iterator for AssocIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            (&node.key, &node.val)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys<V>(list: &AssocList<&'static str, V>) -> Vec<&'static str> {
+        list.iter().map(|(k, _)| *k).collect()
+    }
+
+    #[test]
+    fn test_new_list() {
+        let list: AssocList<i32, i32> = AssocList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.get(&1), None);
+        assert_eq!(format!("{:?}", list), "{}");
+    }
+
+    #[test]
+    fn test_insert_get() {
+        let mut list = AssocList::new();
+        assert_eq!(list.insert("a", 1), None);
+        assert_eq!(list.insert("b", 2), None);
+        assert_eq!(list.insert("c", 3), None);
+        assert_eq!(list.len(), 3);
+
+        // 新键头插
+        assert_eq!(keys(&list), vec!["c", "b", "a"]);
+        assert_eq!(list.get(&"b"), Some(&2));
+        assert!(!list.contains_key(&"d"));
+
+        // 替换不改变长度和顺序
+        assert_eq!(list.insert("a", 10), Some(1));
+        assert_eq!(list.len(), 3);
+        assert_eq!(keys(&list), vec!["c", "b", "a"]);
+
+        *list.get_mut(&"b").unwrap() += 5;
+        assert_eq!(format!("{:?}", list), r#"{"c": 3, "b": 7, "a": 10}"#);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list: AssocList<_, _> = [(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')].into_iter().collect();
+
+        // 中间、表尾、表头
+        assert_eq!(list.remove(&2), Some('b'));
+        assert_eq!(list.remove(&1), Some('a'));
+        assert_eq!(list.remove(&4), Some('d'));
+        assert_eq!(list.remove(&4), None);
+        assert_eq!(list.len(), 1);
+
+        assert_eq!(list.remove(&3), Some('c'));
+        assert!(list.is_empty());
+        assert_eq!(list.remove(&3), None);
+    }
+
+    #[test]
+    fn test_move_to_front() {
+        let mut list = AssocList::with_move_to_front();
+        list.insert("a", 1);
+        list.insert("b", 2);
+        list.insert("c", 3);
+        assert_eq!(keys(&list), vec!["c", "b", "a"]);
+
+        // get 不改变顺序
+        assert_eq!(list.get(&"a"), Some(&1));
+        assert_eq!(keys(&list), vec!["c", "b", "a"]);
+
+        assert_eq!(list.get_mut(&"a"), Some(&mut 1));
+        assert_eq!(keys(&list), vec!["a", "c", "b"]);
+
+        assert_eq!(list.insert("b", 20), Some(2));
+        assert_eq!(keys(&list), vec!["b", "a", "c"]);
+        assert_eq!(list.len(), 3);
+
+        // 未命中不改变顺序
+        assert_eq!(list.get_mut(&"z"), None);
+        assert_eq!(keys(&list), vec!["b", "a", "c"]);
+
+        assert_eq!(list.get_mtf(&"c"), Some(&3));
+        assert_eq!(keys(&list), vec!["c", "b", "a"]);
+        assert_eq!(list.get_mtf(&"z"), None);
+        assert_eq!(keys(&list), vec!["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_get_mtf_disabled() {
+        let mut list: AssocList<_, _> = [("a", 1), ("b", 2)].into_iter().collect();
+
+        // 未启用时与 get 相同
+        assert_eq!(list.get_mtf(&"a"), Some(&1));
+        assert_eq!(keys(&list), vec!["b", "a"]);
+    }
+}
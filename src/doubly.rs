@@ -20,6 +20,7 @@
 //! - prev 指针：NonNull<Node<T>> 仅保持对前一个节点的引用
 //! - 整体所有权：从 head 开始，通过 next 指针链形成完整的所有权链
 
+use std::marker::PhantomData;
 use std::ptr::NonNull;
 use std::fmt::Display;
 use derive_new::new;
@@ -52,6 +53,14 @@ impl<T> DoublyLinkList<T> {
         self.len
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref() }
+    }
+
     pub fn push_back(&mut self, val: T) {
         let mut new_box = Box::new(Node::new(val));
         new_box.prev = self.tail;  // Copy语义
@@ -126,6 +135,155 @@ impl<T> DoublyLinkList<T> {
             old_head.val
         })
     }
+
+    pub(crate) fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|mut tail| unsafe { &mut tail.as_mut().val })
+    }
+
+    /// 摘下头节点原样接到 `dst` 表尾，不重新分配，空链表时什么都不做
+    pub(crate) fn move_front_to(&mut self, dst: &mut Self) {
+        let Some(mut node) = self.head.take() else {
+            return;
+        };
+        self.len -= 1;
+
+        match node.next.take() {
+            Some(mut next) => {
+                next.prev = None;
+                self.head = Some(next);
+            }
+            None => self.tail = None,
+        }
+
+        node.prev = dst.tail;
+        let p = NonNull::from(node.as_mut());
+        match dst.tail {
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(node) },
+            None => dst.head = Some(node),
+        }
+        dst.tail = Some(p);
+        dst.len += 1;
+    }
+
+    /// 指向头节点的可变游标，空链表时游标位于尾后
+    pub(crate) fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let cur = self.head.as_deref_mut().map(NonNull::from);
+        CursorMut { list: self, cur }
+    }
+
+    /// 尾部插入并返回指向新节点的句柄，之后可用 [`remove_node`](Self::remove_node) O(1) 删除
+    pub(crate) fn push_back_node(&mut self, val: T) -> NodeHandle<T> {
+        self.push_back(val);
+        NodeHandle { node: self.tail.unwrap(), _marker: PhantomData }
+    }
+
+    /// 通过句柄 O(1) 删除节点并返回其值
+    ///
+    /// # Safety
+    /// `handle` 必须由当前链表的 [`push_back_node`](Self::push_back_node) 返回，
+    /// 且对应节点尚未被移除
+    pub(crate) unsafe fn remove_node(&mut self, handle: NodeHandle<T>) -> T {
+        let mut node = handle.node;
+        self.len -= 1;
+
+        let prev = node.as_mut().prev;
+        let mut next = node.as_mut().next.take();
+
+        // next.prev -> prev
+        match next.as_mut() {
+            Some(next) => next.prev = prev,
+            None => self.tail = prev,
+        }
+
+        // prev.next -> next，同时取回被删节点的所有权
+        let removed = match prev {
+            Some(mut prev) => std::mem::replace(&mut prev.as_mut().next, next),
+            None => std::mem::replace(&mut self.head, next),
+        };
+
+        removed.unwrap().val
+    }
+}
+
+/// 指向链表中某个节点的句柄，不拥有所有权
+#[derive(Debug)]
+pub(crate) struct NodeHandle<T> {
+    node: NonNull<Node<T>>,
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+/// 可变游标，独占借用链表，可在当前位置原地插入和删除
+pub(crate) struct CursorMut<'a, T> {
+    list: &'a mut DoublyLinkList<T>,
+    // None 表示尾后位置
+    cur: Option<NonNull<Node<T>>>,
+}
+
+impl<T> CursorMut<'_, T> {
+    pub(crate) fn current(&mut self) -> Option<&mut T> {
+        self.cur.map(|mut node| unsafe { &mut node.as_mut().val })
+    }
+
+    /// 移到下一个节点，已在尾后时不动
+    pub(crate) fn move_next(&mut self) {
+        if let Some(mut node) = self.cur {
+            self.cur = unsafe { node.as_mut().next.as_deref_mut().map(NonNull::from) };
+        }
+    }
+
+    /// 在当前节点之前插入，位于尾后时插到表尾
+    pub(crate) fn insert_before(&mut self, val: T) {
+        let Some(mut next) = self.cur else {
+            return self.list.push_back(val);
+        };
+
+        unsafe {
+            let Some(mut prev) = next.as_ref().prev else {
+                return self.list.push_front(val);
+            };
+
+            // prev -> new -> next
+            let mut new_box = Box::new(Node::new(val));
+            new_box.prev = Some(prev);
+            next.as_mut().prev = NonNull::new(new_box.as_mut());
+            new_box.next = prev.as_mut().next.take();
+            prev.as_mut().next = Some(new_box);
+        }
+
+        self.list.len += 1;
+    }
+
+    /// 删除当前节点并移到下一个节点
+    pub(crate) fn remove_current(&mut self) -> Option<T> {
+        let node = self.cur?;
+        self.move_next();
+
+        // 游标独占借用链表，当前节点一定仍在链表中
+        unsafe { Some(self.list.remove_node(NodeHandle { node, _marker: PhantomData })) }
+    }
+}
+
+/// 从头到尾的借用迭代器
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.val
+        })
+    }
+}
+
+// 逐个弹出节点，避免 Box 链递归析构在长链表上栈溢出
+impl<T> Drop for DoublyLinkList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
 }
 
 impl<T: Display> Display for DoublyLinkList<T> {
@@ -222,4 +380,107 @@ mod test {
         assert_eq!(list.to_string(), "LinkList [1, 4]");
         assert_eq!(list.len(), 2);
     }
+
+    #[test]
+    fn test_iter() {
+        let mut list = DoublyLinkList::new();
+        assert_eq!(list.iter().next(), None);
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_front(0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_move_front_to() {
+        let mut src = DoublyLinkList::new();
+        let mut dst = DoublyLinkList::new();
+        src.push_back(1);
+        src.push_back(2);
+
+        src.move_front_to(&mut dst);
+        src.move_front_to(&mut dst);
+        src.move_front_to(&mut dst);
+        assert!(src.is_empty());
+        assert_eq!(dst.to_string(), "LinkList [1, 2]");
+
+        *dst.back_mut().unwrap() = 3;
+        assert_eq!(dst.pop_back(), Some(3));
+        assert_eq!(dst.pop_back(), Some(1));
+        assert_eq!(dst.back_mut(), None);
+
+        // 被摘空的链表仍可正常使用
+        src.push_front(4);
+        assert_eq!(src.pop_back(), Some(4));
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut list = DoublyLinkList::new();
+
+        // 空表：插到表尾
+        list.cursor_front_mut().insert_before(2);
+        // 表头之前
+        list.cursor_front_mut().insert_before(0);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.insert_before(1);
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.insert_before(3);
+        cursor.move_next();
+        assert_eq!(list.to_string(), "LinkList [0, 1, 2, 3]");
+        assert_eq!(list.len(), 4);
+
+        // 删除表头、中间、表尾
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), Some(0));
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.remove_current(), None);
+        assert_eq!(list.to_string(), "LinkList [1]");
+
+        // 链接关系保持正确
+        list.push_back(4);
+        list.push_front(5);
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), Some(5));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_remove_node() {
+        let mut list = DoublyLinkList::new();
+        let a = list.push_back_node(1);
+        let b = list.push_back_node(2);
+        let c = list.push_back_node(3);
+        let d = list.push_back_node(4);
+
+        unsafe {
+            // 中间节点
+            assert_eq!(list.remove_node(b), 2);
+            assert_eq!(list.to_string(), "LinkList [1, 3, 4]");
+            // 尾节点
+            assert_eq!(list.remove_node(d), 4);
+            assert_eq!(list.to_string(), "LinkList [1, 3]");
+            // 头节点
+            assert_eq!(list.remove_node(a), 1);
+            assert_eq!(list.to_string(), "LinkList [3]");
+            // 唯一节点
+            assert_eq!(list.remove_node(c), 3);
+        }
+        assert!(list.is_empty());
+
+        // 删除后链表仍可正常使用
+        list.push_back(5);
+        list.push_front(6);
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.pop_back(), Some(6));
+        assert_eq!(list.pop_back(), None);
+    }
 }
@@ -0,0 +1,335 @@
+//! 基于邻接链表的有向图实现
+//!
+//! 这个模块提供了一个轻量的有向图。每个顶点拥有一条 [`DoublyLinkList`] 作为邻接表，
+//! 表中的节点保存 `(目标顶点, 边权)`。
+//!
+//! # 设计特点
+//!
+//! - **O(1) 增删边**：加边时返回 [`EdgeHandle`]，删边时直接通过句柄摘除链表节点
+//! - **线性句柄**：`EdgeHandle` 不可复制，删除时被消耗，避免重复删除同一条边
+//! - **图标识**：每个图有唯一 id，句柄只能用于创建它的图
+//! - **遍历**：提供 [`Bfs`] 与 [`Dfs`] 两种惰性遍历迭代器
+//! - **越界约定**：`vertex`/`vertex_mut` 越界返回 `None`，其余接收顶点编号的方法越界时 panic
+//!
+//! # 内存布局
+//! ```text
+//! vertices
+//!   [0] --> (1, e01) <-> (2, e02)
+//!   [1] --> (2, e12)
+//!   [2] --> (empty)
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::doubly::{DoublyLinkList, NodeHandle};
+
+// 为每个图分配唯一 id
+static NEXT_GRAPH_ID: AtomicUsize = AtomicUsize::new(0);
+
+struct Vertex<V, E> {
+    val: V,
+    edges: DoublyLinkList<(usize, E)>,
+}
+
+/// 边句柄，由 [`Graph::add_edge`] 返回，用于 O(1) 删除该边
+#[derive(Debug)]
+pub struct EdgeHandle<E> {
+    graph: usize,
+    from: usize,
+    node: NodeHandle<(usize, E)>,
+}
+
+impl<E> EdgeHandle<E> {
+    pub fn from(&self) -> usize {
+        self.from
+    }
+}
+
+pub struct Graph<V, E = ()> {
+    id: usize,
+    vertices: Vec<Vertex<V, E>>,
+    edge_count: usize,
+}
+
+impl<V, E> Graph<V, E> {
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_GRAPH_ID.fetch_add(1, Ordering::Relaxed),
+            vertices: Vec::new(),
+            edge_count: 0,
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// 添加顶点，返回顶点编号
+    pub fn add_vertex(&mut self, val: V) -> usize {
+        self.vertices.push(Vertex { val, edges: DoublyLinkList::new() });
+        self.vertices.len() - 1
+    }
+
+    /// 顶点 `v` 的数据，越界时返回 `None`
+    pub fn vertex(&self, v: usize) -> Option<&V> {
+        self.vertices.get(v).map(|vertex| &vertex.val)
+    }
+
+    pub fn vertex_mut(&mut self, v: usize) -> Option<&mut V> {
+        self.vertices.get_mut(v).map(|vertex| &mut vertex.val)
+    }
+
+    fn check_vertex(&self, v: usize) {
+        assert!(v < self.vertices.len(), "vertex {} out of bounds", v);
+    }
+
+    /// 添加一条 `from -> to` 的边
+    ///
+    /// # Panics
+    /// 顶点编号越界时 panic
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: E) -> EdgeHandle<E> {
+        self.check_vertex(from);
+        self.check_vertex(to);
+        let node = self.vertices[from].edges.push_back_node((to, weight));
+        self.edge_count += 1;
+
+        EdgeHandle { graph: self.id, from, node }
+    }
+
+    /// 通过句柄删除边，返回 `(目标顶点, 边权)`
+    ///
+    /// # Panics
+    /// 句柄不属于当前图时 panic
+    pub fn remove_edge(&mut self, edge: EdgeHandle<E>) -> (usize, E) {
+        assert_eq!(edge.graph, self.id, "edge handle belongs to another graph");
+        self.edge_count -= 1;
+
+        // 句柄由本图创建且不可复制，对应节点一定仍在 from 的邻接表中
+        unsafe { self.vertices[edge.from].edges.remove_node(edge.node) }
+    }
+
+    /// 顶点 `v` 的出边，按插入顺序返回 `(目标顶点, 边权)`
+    ///
+    /// # Panics
+    /// 顶点编号越界时 panic
+    pub fn neighbors(&self, v: usize) -> impl Iterator<Item = (usize, &E)> {
+        self.check_vertex(v);
+        self.vertices[v].edges.iter().map(|(to, weight)| (*to, weight))
+    }
+
+    /// 顶点 `v` 的出度
+    ///
+    /// # Panics
+    /// 顶点编号越界时 panic
+    pub fn out_degree(&self, v: usize) -> usize {
+        self.check_vertex(v);
+        self.vertices[v].edges.len()
+    }
+
+    /// 从 `start` 开始的广度优先遍历
+    ///
+    /// # Panics
+    /// 顶点编号越界时 panic
+    pub fn bfs(&self, start: usize) -> Bfs<'_, V, E> {
+        self.check_vertex(start);
+        let mut visited = vec![false; self.vertices.len()];
+        visited[start] = true;
+
+        Bfs { graph: self, queue: VecDeque::from([start]), visited }
+    }
+
+    /// 从 `start` 开始的深度优先遍历（先序）
+    ///
+    /// # Panics
+    /// 顶点编号越界时 panic
+    pub fn dfs(&self, start: usize) -> Dfs<'_, V, E> {
+        self.check_vertex(start);
+
+        Dfs { graph: self, stack: vec![start], visited: vec![false; self.vertices.len()] }
+    }
+}
+
+impl<V, E> Default for Graph<V, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 广度优先遍历迭代器，依次产出顶点编号
+pub struct Bfs<'a, V, E> {
+    graph: &'a Graph<V, E>,
+    queue: VecDeque<usize>,
+    visited: Vec<bool>,
+}
+
+impl<V, E> Iterator for Bfs<'_, V, E> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let v = self.queue.pop_front()?;
+
+        // 入队时标记，保证每个顶点只入队一次
+        for (to, _) in self.graph.neighbors(v) {
+            if !self.visited[to] {
+                self.visited[to] = true;
+                self.queue.push_back(to);
+            }
+        }
+
+        Some(v)
+    }
+}
+
+/// 深度优先遍历迭代器，依次产出顶点编号
+pub struct Dfs<'a, V, E> {
+    graph: &'a Graph<V, E>,
+    stack: Vec<usize>,
+    visited: Vec<bool>,
+}
+
+impl<V, E> Iterator for Dfs<'_, V, E> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        // 出栈时标记，跳过已访问的顶点
+        while let Some(v) = self.stack.pop() {
+            if self.visited[v] {
+                continue;
+            }
+            self.visited[v] = true;
+
+            // 逆序压栈，使邻居按插入顺序被访问
+            let start = self.stack.len();
+            self.stack.extend(
+                self.graph.neighbors(v).map(|(to, _)| to).filter(|&to| !self.visited[to]),
+            );
+            self.stack[start..].reverse();
+
+            return Some(v);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3
+    // |         ^
+    // v         |
+    // 2 --------+
+    fn sample() -> Graph<&'static str, u32> {
+        let mut g = Graph::new();
+        for name in ["a", "b", "c", "d"] {
+            g.add_vertex(name);
+        }
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 2, 2);
+        g.add_edge(1, 3, 3);
+        g.add_edge(2, 3, 4);
+        g
+    }
+
+    #[test]
+    fn test_add_vertex_edge() {
+        let g = sample();
+        assert_eq!(g.vertex_count(), 4);
+        assert_eq!(g.edge_count(), 4);
+        assert_eq!(g.vertex(2), Some(&"c"));
+        assert_eq!(g.vertex(4), None);
+        assert_eq!(g.neighbors(0).collect::<Vec<_>>(), vec![(1, &1), (2, &2)]);
+        assert_eq!(g.out_degree(3), 0);
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut g: Graph<(), ()> = Graph::new();
+        for _ in 0..4 {
+            g.add_vertex(());
+        }
+        let e1 = g.add_edge(0, 1, ());
+        let e2 = g.add_edge(0, 2, ());
+        let e3 = g.add_edge(0, 3, ());
+
+        // 删除中间的边
+        assert_eq!(g.remove_edge(e2), (2, ()));
+        assert_eq!(g.neighbors(0).map(|(to, _)| to).collect::<Vec<_>>(), vec![1, 3]);
+
+        assert_eq!(e1.from(), 0);
+        g.remove_edge(e3);
+        g.remove_edge(e1);
+        assert_eq!(g.edge_count(), 0);
+        assert_eq!(g.neighbors(0).count(), 0);
+
+        // 删除后仍可继续加边
+        g.add_edge(0, 3, ());
+        assert_eq!(g.neighbors(0).map(|(to, _)| to).collect::<Vec<_>>(), vec![3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "another graph")]
+    fn test_remove_foreign_edge() {
+        let mut g1: Graph<()> = Graph::new();
+        let mut g2: Graph<()> = Graph::new();
+        g1.add_vertex(());
+        g2.add_vertex(());
+
+        let e = g1.add_edge(0, 0, ());
+        g2.remove_edge(e);
+    }
+
+    #[test]
+    #[should_panic(expected = "vertex 5 out of bounds")]
+    fn test_add_edge_bad_from() {
+        let mut g = sample();
+        g.add_edge(5, 0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "vertex 4 out of bounds")]
+    fn test_neighbors_bad_vertex() {
+        let g = sample();
+        let _ = g.neighbors(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "vertex 4 out of bounds")]
+    fn test_out_degree_bad_vertex() {
+        sample().out_degree(4);
+    }
+
+    #[test]
+    fn test_bfs() {
+        let g = sample();
+        assert_eq!(g.bfs(0).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(g.bfs(2).collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let g = sample();
+        assert_eq!(g.dfs(0).collect::<Vec<_>>(), vec![0, 1, 3, 2]);
+        assert_eq!(g.dfs(3).collect::<Vec<_>>(), vec![3]);
+    }
+
+    #[test]
+    fn test_traverse_cycle() {
+        let mut g: Graph<()> = Graph::new();
+        for _ in 0..3 {
+            g.add_vertex(());
+        }
+        g.add_edge(0, 1, ());
+        g.add_edge(1, 2, ());
+        g.add_edge(2, 0, ());
+
+        assert_eq!(g.bfs(1).collect::<Vec<_>>(), vec![1, 2, 0]);
+        assert_eq!(g.dfs(2).collect::<Vec<_>>(), vec![2, 0, 1]);
+    }
+}
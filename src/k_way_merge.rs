@@ -0,0 +1,182 @@
+//! 多路归并迭代器
+//!
+//! 这个模块提供了对多条有序 [`DoublyLinkList`] 的惰性归并。内部维护一个小根堆，
+//! 堆中只保存每条链表当前的队首元素，每产出一个元素只需一次出堆和一次入堆。
+//!
+//! # 设计特点
+//!
+//! - **惰性求值**：只在调用 `next` 时才从链表中弹出元素
+//! - **复杂度**：总共 n 个元素、k 条链表时为 O(n log k)，只需一趟
+//! - **稳定性**：相等元素按链表在输入中的顺序产出
+//!
+//! # 堆结构
+//! ```text
+//!            heap (min)
+//!      (1, #0)  (2, #2)  (4, #1)
+//!         |        |        |
+//!         v        v        v
+//! #0: [.., 3, 7]  #2: [.., 5]  #1: [.., 9]
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::doubly::DoublyLinkList;
+
+/// 归并多条升序链表，返回升序的惰性迭代器
+pub fn merge_k<T: Ord>(lists: Vec<DoublyLinkList<T>>) -> KWayMerge<T> {
+    KWayMerge::new(lists)
+}
+
+pub struct KWayMerge<T> {
+    lists: Vec<DoublyLinkList<T>>,
+    // (队首元素, 链表下标)，下标参与比较以保证稳定性
+    heap: BinaryHeap<Reverse<(T, usize)>>,
+}
+
+impl<T: Ord> KWayMerge<T> {
+    pub fn new(mut lists: Vec<DoublyLinkList<T>>) -> Self {
+        let heap = lists
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(i, list)| list.pop_front().map(|val| Reverse((val, i))))
+            .collect();
+
+        Self { lists, heap }
+    }
+}
+
+impl<T: Ord> Iterator for KWayMerge<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let Reverse((val, i)) = self.heap.pop()?;
+
+        // 补充同一条链表的下一个元素
+        if let Some(next) = self.lists[i].pop_front() {
+            self.heap.push(Reverse((next, i)));
+        }
+
+        Some(val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Ord> ExactSizeIterator for KWayMerge<T> {
+    fn len(&self) -> usize {
+        self.heap.len() + self.lists.iter().map(DoublyLinkList::len).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+    use super::*;
+
+    fn list_of(vals: &[i32]) -> DoublyLinkList<i32> {
+        let mut list = DoublyLinkList::new();
+        for &val in vals {
+            list.push_back(val);
+        }
+        list
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(merge_k::<i32>(vec![]).next(), None);
+
+        // 全部为空链表
+        let merged = merge_k(vec![list_of(&[]), list_of(&[])]);
+        assert_eq!(merged.len(), 0);
+        assert_eq!(merged.collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn test_merge() {
+        let lists = vec![
+            list_of(&[1, 4, 7]),
+            list_of(&[]),
+            list_of(&[2, 5, 8, 10]),
+            list_of(&[3, 6, 9]),
+        ];
+
+        let mut merged = merge_k(lists);
+        assert_eq!(merged.len(), 10);
+        assert_eq!(merged.next(), Some(1));
+        assert_eq!(merged.len(), 9);
+        assert_eq!(merged.collect::<Vec<_>>(), vec![2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_stable() {
+        // 只按 key 比较，src 记录来源链表
+        #[derive(Debug)]
+        struct Item {
+            key: i32,
+            src: char,
+        }
+
+        impl PartialEq for Item {
+            fn eq(&self, other: &Self) -> bool {
+                self.key == other.key
+            }
+        }
+        impl Eq for Item {}
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+
+        let mut a = DoublyLinkList::new();
+        let mut b = DoublyLinkList::new();
+        for key in [1, 2] {
+            b.push_back(Item { key, src: 'b' });
+            a.push_back(Item { key, src: 'a' });
+        }
+
+        // b 在前，相等元素先产出 b 的
+        let merged: Vec<_> = merge_k(vec![b, a]).map(|item| (item.key, item.src)).collect();
+        assert_eq!(merged, vec![(1, 'b'), (1, 'a'), (2, 'b'), (2, 'a')]);
+    }
+
+    #[test]
+    fn test_drop_partially_consumed() {
+        let mut list = DoublyLinkList::new();
+        for i in 0..1_000_000 {
+            list.push_back(i);
+        }
+
+        // 提前停止后丢弃，剩余的长链表不能递归析构
+        let mut merged = merge_k(vec![list, list_of(&[0])]);
+        assert_eq!(merged.next(), Some(0));
+        drop(merged);
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = rand::thread_rng();
+        let mut expected = vec![];
+        let mut lists = vec![];
+
+        // 随机生成 20 条有序链表
+        for _ in 0..20 {
+            let mut vals: Vec<i32> = (0..rng.gen_range(0..50)).map(|_| rng.gen_range(-100..100)).collect();
+            vals.sort();
+            expected.extend_from_slice(&vals);
+            lists.push(list_of(&vals));
+        }
+        expected.sort();
+
+        assert_eq!(merge_k(lists).collect::<Vec<_>>(), expected);
+    }
+}
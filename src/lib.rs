@@ -1,8 +1,18 @@
+mod assoc_list;
 mod doubly;
+mod graph;
+mod k_way_merge;
+mod pool;
+mod sparse_poly;
 mod two_lock_queue;
 
 // 重新导出数据结构供外部使用
-pub use doubly::DoublyLinkList;
+pub use assoc_list::{AssocIter, AssocList};
+pub use doubly::{DoublyLinkList, Iter};
+pub use graph::{Bfs, Dfs, EdgeHandle, Graph};
+pub use k_way_merge::{merge_k, KWayMerge};
+pub use pool::{Pool, PoolGuard, SyncPool, SyncPoolGuard};
+pub use sparse_poly::SparsePoly;
 pub use two_lock_queue::TwoLockQueue;
 
 #[cfg(test)]
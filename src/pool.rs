@@ -0,0 +1,412 @@
+//! 对象池实现
+//!
+//! 这个模块提供了两种对象池，用于复用创建代价较高的对象（如连接、缓冲区）：
+//!
+//! - [`Pool`]：单线程版本，空闲对象保存在 [`DoublyLinkList`] 中
+//! - [`SyncPool`]：线程安全版本，空闲对象保存在 [`TwoLockQueue`] 中
+//!
+//! # 设计特点
+//!
+//! - **RAII 归还**：`get` 返回守卫，守卫析构时对象自动归还到池中
+//! - **按需创建**：池中没有空闲对象时调用 `init` 钩子创建新对象
+//! - **重置钩子**：可选的 `reset` 钩子在对象归还前调用，清理上一次使用留下的状态
+//! - **脱离池**：`detach` 取出对象的所有权，对象不再归还
+//! - **panic 时丢弃**：守卫在 panic 展开中析构时直接丢弃对象，不归还也不调用 `reset`，
+//!   避免把状态不完整的对象交给下一个使用者
+//! - **复用顺序**：`Pool` 后进先出，最近归还的对象优先复用；`SyncPool` 先进先出，
+//!   空闲最久的对象优先复用
+//!
+//! # 对象流转
+//! ```text
+//!          get (pop / init)
+//!  +------+ ------------------> +-------+
+//!  | free |                     | guard |
+//!  +------+ <------------------ +-------+
+//!          drop (reset / push)
+//! ```
+
+use std::cell::RefCell;
+use std::ops::{Deref, DerefMut};
+
+use crate::doubly::DoublyLinkList;
+use crate::two_lock_queue::TwoLockQueue;
+
+type Init<T> = Box<dyn Fn() -> T>;
+type Reset<T> = Box<dyn Fn(&mut T)>;
+type SyncInit<T> = Box<dyn Fn() -> T + Send + Sync>;
+type SyncReset<T> = Box<dyn Fn(&mut T) + Send + Sync>;
+
+/// 单线程对象池
+///
+/// 空闲对象按后进先出（LIFO）复用：`get` 总是取回最近归还的对象。
+pub struct Pool<T> {
+    // 空闲对象，头部进出
+    free: RefCell<DoublyLinkList<T>>,
+    init: Init<T>,
+    reset: Option<Reset<T>>,
+}
+
+impl<T: Default + 'static> Pool<T> {
+    /// 使用 `T::default` 创建新对象的池
+    pub fn new() -> Self {
+        Self::with_init(T::default)
+    }
+}
+
+impl<T: Default + 'static> Default for Pool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Pool<T> {
+    /// 使用 `init` 创建新对象的池
+    pub fn with_init(init: impl Fn() -> T + 'static) -> Self {
+        Self { free: RefCell::new(DoublyLinkList::new()), init: Box::new(init), reset: None }
+    }
+
+    /// 设置归还前调用的重置钩子
+    pub fn with_reset(mut self, reset: impl Fn(&mut T) + 'static) -> Self {
+        self.reset = Some(Box::new(reset));
+        self
+    }
+
+    /// 取出一个对象，没有空闲对象时新建
+    pub fn get(&self) -> PoolGuard<'_, T> {
+        // 先释放借用再调用 init，避免 init 中再次访问池时 panic
+        let val = self.free.borrow_mut().pop_front();
+        let val = val.unwrap_or_else(|| (self.init)());
+
+        PoolGuard { pool: self, val: Some(val) }
+    }
+
+    /// 空闲对象个数
+    pub fn available(&self) -> usize {
+        self.free.borrow().len()
+    }
+
+    fn recycle(&self, mut val: T) {
+        if let Some(reset) = &self.reset {
+            reset(&mut val);
+        }
+        self.free.borrow_mut().push_front(val);
+    }
+}
+
+/// [`Pool::get`] 返回的守卫，析构时把对象归还到池中，panic 展开中析构时丢弃对象
+pub struct PoolGuard<'a, T> {
+    pool: &'a Pool<T>,
+    // 只在 detach 或 drop 时为 None
+    val: Option<T>,
+}
+
+impl<T> PoolGuard<'_, T> {
+    /// 取出对象的所有权，对象不再归还到池中
+    pub fn detach(mut self) -> T {
+        self.val.take().unwrap()
+    }
+}
+
+impl<T> Deref for PoolGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.val.as_ref().unwrap()
+    }
+}
+
+impl<T> DerefMut for PoolGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.val.as_mut().unwrap()
+    }
+}
+
+impl<T> Drop for PoolGuard<'_, T> {
+    fn drop(&mut self) {
+        // panic 展开中对象可能只改了一半，直接丢弃
+        if std::thread::panicking() {
+            return;
+        }
+        if let Some(val) = self.val.take() {
+            self.pool.recycle(val);
+        }
+    }
+}
+
+/// 线程安全的对象池
+///
+/// 空闲对象按先进先出（FIFO）复用：`get` 取回空闲最久的对象，
+/// 这与 [`Pool`] 的顺序相反。
+pub struct SyncPool<T> {
+    // 空闲对象，尾进头出
+    free: TwoLockQueue<T>,
+    init: SyncInit<T>,
+    reset: Option<SyncReset<T>>,
+}
+
+impl<T: Default + 'static> SyncPool<T> {
+    /// 使用 `T::default` 创建新对象的池
+    pub fn new() -> Self {
+        Self::with_init(T::default)
+    }
+}
+
+impl<T: Default + 'static> Default for SyncPool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SyncPool<T> {
+    /// 使用 `init` 创建新对象的池
+    pub fn with_init(init: impl Fn() -> T + Send + Sync + 'static) -> Self {
+        Self { free: TwoLockQueue::new(), init: Box::new(init), reset: None }
+    }
+
+    /// 设置归还前调用的重置钩子
+    pub fn with_reset(mut self, reset: impl Fn(&mut T) + Send + Sync + 'static) -> Self {
+        self.reset = Some(Box::new(reset));
+        self
+    }
+
+    /// 取出一个对象，没有空闲对象时新建
+    pub fn get(&self) -> SyncPoolGuard<'_, T> {
+        let val = self.free.pop().unwrap_or_else(|| (self.init)());
+
+        SyncPoolGuard { pool: self, val: Some(val) }
+    }
+
+    /// 空闲对象个数，并发情况下只是一个近似值
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+
+    fn recycle(&self, mut val: T) {
+        if let Some(reset) = &self.reset {
+            reset(&mut val);
+        }
+        self.free.push(val);
+    }
+}
+
+/// [`SyncPool::get`] 返回的守卫，析构时把对象归还到池中，panic 展开中析构时丢弃对象
+pub struct SyncPoolGuard<'a, T> {
+    pool: &'a SyncPool<T>,
+    // 只在 detach 或 drop 时为 None
+    val: Option<T>,
+}
+
+impl<T> SyncPoolGuard<'_, T> {
+    /// 取出对象的所有权，对象不再归还到池中
+    pub fn detach(mut self) -> T {
+        self.val.take().unwrap()
+    }
+}
+
+impl<T> Deref for SyncPoolGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.val.as_ref().unwrap()
+    }
+}
+
+impl<T> DerefMut for SyncPoolGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.val.as_mut().unwrap()
+    }
+}
+
+impl<T> Drop for SyncPoolGuard<'_, T> {
+    fn drop(&mut self) {
+        // panic 展开中对象可能只改了一半，直接丢弃
+        if std::thread::panicking() {
+            return;
+        }
+        if let Some(val) = self.val.take() {
+            self.pool.recycle(val);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use super::*;
+
+    #[test]
+    fn test_reuse() {
+        let pool: Pool<Vec<u8>> = Pool::new();
+        assert_eq!(pool.available(), 0);
+
+        {
+            let mut buf = pool.get();
+            buf.push(1);
+            assert_eq!(pool.available(), 0);
+        }
+        assert_eq!(pool.available(), 1);
+
+        // 没有重置钩子，对象原样复用
+        let buf = pool.get();
+        assert_eq!(*buf, vec![1]);
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    fn test_init_reset() {
+        let created = Rc::new(Cell::new(0));
+        let counter = created.clone();
+        let pool = Pool::with_init(move || {
+            counter.set(counter.get() + 1);
+            Vec::with_capacity(16)
+        })
+        .with_reset(|buf: &mut Vec<i32>| buf.clear());
+
+        {
+            let mut a = pool.get();
+            let mut b = pool.get();
+            a.push(1);
+            b.push(2);
+        }
+        assert_eq!(created.get(), 2);
+        assert_eq!(pool.available(), 2);
+
+        // 复用时不再创建，且已被重置
+        let a = pool.get();
+        let b = pool.get();
+        assert!(a.is_empty() && b.is_empty());
+        assert!(a.capacity() >= 16);
+        assert_eq!(created.get(), 2);
+
+        // 池空后按需创建
+        let _c = pool.get();
+        assert_eq!(created.get(), 3);
+    }
+
+    #[test]
+    fn test_detach() {
+        let pool = Pool::with_init(|| String::from("conn"));
+
+        let conn = pool.get().detach();
+        assert_eq!(conn, "conn");
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    fn test_discard_on_panic() {
+        let resets = Rc::new(Cell::new(0));
+        let counter = resets.clone();
+        let pool = Pool::with_init(Vec::new).with_reset(move |buf: &mut Vec<i32>| {
+            counter.set(counter.get() + 1);
+            buf.clear();
+        });
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut buf = pool.get();
+            buf.push(1);
+            panic!("half-written");
+        }));
+
+        // 对象被丢弃，reset 没有被调用
+        assert!(result.is_err());
+        assert_eq!(pool.available(), 0);
+        assert_eq!(resets.get(), 0);
+        assert!(pool.get().is_empty());
+    }
+
+    #[test]
+    fn test_sync_discard_on_panic() {
+        let pool = SyncPool::with_init(Vec::new).with_reset(|_: &mut Vec<i32>| panic!("reset during unwind"));
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut buf = pool.get();
+            buf.push(1);
+            panic!("half-written");
+        }));
+
+        // reset 会 panic，若在展开中被调用进程会直接 abort
+        assert!(result.is_err());
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    fn test_lifo_order() {
+        let pool = Pool::with_init(|| 0);
+        {
+            let mut a = pool.get();
+            let mut b = pool.get();
+            *a = 1;
+            *b = 2;
+            drop(a);
+        }
+
+        // b 最后归还，最先取出
+        assert_eq!(*pool.get(), 2);
+    }
+
+    #[test]
+    fn test_fifo_order() {
+        let pool = SyncPool::with_init(|| 0);
+        {
+            let mut a = pool.get();
+            let mut b = pool.get();
+            *a = 1;
+            *b = 2;
+            drop(a);
+        }
+
+        // a 最先归还，最先取出
+        assert_eq!(*pool.get(), 1);
+    }
+
+    #[test]
+    fn test_sync_pool() {
+        let created = Arc::new(AtomicUsize::new(0));
+        let counter = created.clone();
+        let pool = Arc::new(
+            SyncPool::with_init(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Vec::new()
+            })
+            .with_reset(|buf: &mut Vec<usize>| buf.clear()),
+        );
+        let mut handles = vec![];
+
+        // 8个线程反复借还
+        for i in 0..8 {
+            let pool = pool.clone();
+            handles.push(thread::spawn(move || {
+                for j in 0..100 {
+                    let mut buf = pool.get();
+                    assert!(buf.is_empty());
+                    buf.push(i * 100 + j);
+                }
+            }));
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // 同一时刻最多 8 个对象在外
+        let created = created.load(Ordering::SeqCst);
+        assert!((1..=8).contains(&created));
+        assert_eq!(pool.available(), created);
+    }
+
+    #[test]
+    fn test_sync_detach() {
+        let pool: SyncPool<i32> = SyncPool::new();
+        {
+            let _a = pool.get();
+            let b = pool.get();
+            assert_eq!(b.detach(), 0);
+        }
+        assert_eq!(pool.available(), 1);
+    }
+}
@@ -0,0 +1,394 @@
+//! 稀疏多项式实现
+//!
+//! 这个模块用有序链表表示稀疏多项式，链表中每个节点保存一个 `(指数, 系数)` 项，
+//! 只存储非零项。
+//!
+//! # 设计特点
+//!
+//! - **有序存储**：项按指数从高到低排列，加法只需一次归并，复杂度 O(n + m)
+//! - **零系数**：以 `C::default()` 作为零，运算结果中的零项会被剔除
+//! - **求值**：按降幂顺序使用 Horner 法则，跳过的指数用快速幂补齐
+//!
+//! # 内存布局
+//! ```text
+//! 3x^5 + 2x^2 + 1
+//!
+//! +--------+    +--------+    +--------+
+//! | (5, 3) |<-->| (2, 2) |<-->| (0, 1) |
+//! +--------+    +--------+    +--------+
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Mul};
+
+use crate::doubly::DoublyLinkList;
+
+pub struct SparsePoly<C> {
+    terms: DoublyLinkList<(u32, C)>,
+}
+
+impl<C> SparsePoly<C> {
+    pub fn new() -> Self {
+        Self { terms: DoublyLinkList::new() }
+    }
+
+    /// 非零项的个数
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// 最高次数，零多项式返回 `None`
+    pub fn degree(&self) -> Option<u32> {
+        self.terms.iter().next().map(|(exp, _)| *exp)
+    }
+
+    /// 按指数从高到低返回 `(指数, 系数)`
+    pub fn terms(&self) -> impl Iterator<Item = (u32, &C)> {
+        self.terms.iter().map(|(exp, coeff)| (*exp, coeff))
+    }
+}
+
+impl<C> SparsePoly<C>
+where
+    C: Clone + Default + PartialEq + Add<Output = C> + Mul<Output = C>,
+{
+    /// 由任意顺序的项构造多项式，相同指数的系数会被合并
+    pub fn from_terms<I: IntoIterator<Item = (u32, C)>>(terms: I) -> Self {
+        // 先按指数降序排序并合并同类项，再一次性建表
+        let mut terms: Vec<_> = terms.into_iter().collect();
+        terms.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        let mut poly = Self::new();
+        let mut terms = terms.into_iter().peekable();
+        while let Some((exp, mut coeff)) = terms.next() {
+            while let Some((_, next)) = terms.next_if(|(e, _)| *e == exp) {
+                coeff = coeff + next;
+            }
+            if coeff != C::default() {
+                poly.terms.push_back((exp, coeff));
+            }
+        }
+        poly
+    }
+
+    /// 指数 `exp` 对应的系数，不存在时返回零
+    pub fn coeff(&self, exp: u32) -> C {
+        self.terms
+            .iter()
+            .find(|(e, _)| *e == exp)
+            .map(|(_, c)| c.clone())
+            .unwrap_or_default()
+    }
+
+    /// 加上一项 `coeff * x^exp`，一次遍历找到位置后原地插入或合并
+    pub fn add_term(&mut self, exp: u32, coeff: C) {
+        if coeff == C::default() {
+            return;
+        }
+
+        // 停在第一个指数不大于 exp 的节点
+        let mut cursor = self.terms.cursor_front_mut();
+        while cursor.current().is_some_and(|(e, _)| *e > exp) {
+            cursor.move_next();
+        }
+
+        match cursor.current() {
+            Some((e, c)) if *e == exp => {
+                *c = std::mem::take(c) + coeff;
+                if *c == C::default() {
+                    cursor.remove_current();
+                }
+            }
+            _ => cursor.insert_before((exp, coeff)),
+        }
+    }
+
+    /// 计算 `p(x)`
+    pub fn evaluate(&self, x: C) -> C {
+        let mut terms = self.terms.iter();
+        let Some((mut prev_exp, first)) = terms.next() else {
+            return C::default();
+        };
+
+        // Horner 法则：acc = acc * x^(prev - exp) + coeff
+        let mut acc = first.clone();
+        for (exp, coeff) in terms {
+            acc = acc * pow(&x, prev_exp - exp) + coeff.clone();
+            prev_exp = *exp;
+        }
+
+        if prev_exp > 0 {
+            acc = acc * pow(&x, prev_exp);
+        }
+        acc
+    }
+}
+
+/// 归并两条降幂有序的项链表，剔除零系数
+///
+/// 节点从输入链表直接移动到结果中，不克隆系数
+fn merge<C>(mut a: DoublyLinkList<(u32, C)>, mut b: DoublyLinkList<(u32, C)>) -> DoublyLinkList<(u32, C)>
+where
+    C: Default + PartialEq + Add<Output = C>,
+{
+    let mut result = DoublyLinkList::new();
+
+    loop {
+        let order = match (a.iter().next(), b.iter().next()) {
+            (Some((ea, _)), Some((eb, _))) => ea.cmp(eb),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => break,
+        };
+
+        match order {
+            Ordering::Greater => a.move_front_to(&mut result),
+            Ordering::Less => b.move_front_to(&mut result),
+            Ordering::Equal => {
+                // 复用 a 的节点，把 b 的系数加进去
+                a.move_front_to(&mut result);
+                let (_, cb) = b.pop_front().unwrap();
+                let (_, c) = result.back_mut().unwrap();
+                *c = std::mem::take(c) + cb;
+                if *c == C::default() {
+                    result.pop_back();
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// 快速幂，要求 `n >= 1`
+fn pow<C: Clone + Mul<Output = C>>(x: &C, mut n: u32) -> C {
+    let mut base = x.clone();
+    let mut acc: Option<C> = None;
+
+    loop {
+        if n & 1 == 1 {
+            acc = Some(match acc {
+                Some(acc) => acc * base.clone(),
+                None => base.clone(),
+            });
+        }
+        n >>= 1;
+        if n == 0 {
+            break;
+        }
+        base = base.clone() * base;
+    }
+
+    acc.unwrap()
+}
+
+impl<C> Default for SparsePoly<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clone> Clone for SparsePoly<C> {
+    fn clone(&self) -> Self {
+        let mut terms = DoublyLinkList::new();
+        for term in self.terms.iter() {
+            terms.push_back(term.clone());
+        }
+        Self { terms }
+    }
+}
+
+impl<C: PartialEq> PartialEq for SparsePoly<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.terms.iter().eq(other.terms.iter())
+    }
+}
+
+impl<C> Add for &SparsePoly<C>
+where
+    C: Clone + Default + PartialEq + Add<Output = C>,
+{
+    type Output = SparsePoly<C>;
+
+    fn add(self, rhs: Self) -> SparsePoly<C> {
+        SparsePoly { terms: merge(self.clone().terms, rhs.clone().terms) }
+    }
+}
+
+impl<C> Mul for &SparsePoly<C>
+where
+    C: Clone + Default + PartialEq + Add<Output = C> + Mul<Output = C>,
+{
+    type Output = SparsePoly<C>;
+
+    /// # Panics
+    /// 乘积的指数超出 `u32` 范围时 panic
+    fn mul(self, rhs: Self) -> SparsePoly<C> {
+        let mut result = SparsePoly::new();
+
+        // 逐项相乘得到降幂有序的部分积，再把节点移动归并进结果，不克隆已累加的系数
+        for (ea, ca) in self.terms.iter() {
+            let mut partial = DoublyLinkList::new();
+            for (eb, cb) in rhs.terms.iter() {
+                let coeff = ca.clone() * cb.clone();
+                if coeff != C::default() {
+                    let exp = ea.checked_add(*eb).expect("exponent overflow in polynomial multiplication");
+                    partial.push_back((exp, coeff));
+                }
+            }
+            let acc = std::mem::replace(&mut result.terms, DoublyLinkList::new());
+            result.terms = merge(acc, partial);
+        }
+
+        result
+    }
+}
+
+impl<C: Debug> Debug for SparsePoly<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.terms.iter()).finish()
+    }
+}
+
+impl<C: Display> Display for SparsePoly<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "0");
+        }
+
+        for (i, (exp, coeff)) in self.terms().enumerate() {
+            // 按格式化结果拆出符号，避免对 C 额外要求 Neg/PartialOrd
+            let coeff = coeff.to_string();
+            let (negative, abs) = match coeff.strip_prefix('-') {
+                Some(abs) => (true, abs),
+                None => (false, coeff.as_str()),
+            };
+
+            match (i, negative) {
+                (0, true) => write!(f, "-")?,
+                (0, false) => {}
+                (_, true) => write!(f, " - ")?,
+                (_, false) => write!(f, " + ")?,
+            }
+
+            // 非常数项省略单位系数
+            if exp == 0 || abs != "1" {
+                write!(f, "{}", abs)?;
+            }
+            match exp {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", exp)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_poly() {
+        let p: SparsePoly<i32> = SparsePoly::new();
+        assert!(p.is_empty());
+        assert_eq!(p.degree(), None);
+        assert_eq!(p.to_string(), "0");
+        assert_eq!(p.evaluate(5), 0);
+    }
+
+    #[test]
+    fn test_from_terms() {
+        // 乱序输入，重复指数合并，零项剔除
+        let p = SparsePoly::from_terms([(0, 1), (5, 3), (2, 2), (2, 4), (3, 0)]);
+        assert_eq!(p.to_string(), "3x^5 + 6x^2 + 1");
+        assert_eq!(p.len(), 3);
+        assert_eq!(p.degree(), Some(5));
+        assert_eq!(p.coeff(2), 6);
+        assert_eq!(p.coeff(4), 0);
+    }
+
+    #[test]
+    fn test_add_term() {
+        let mut p = SparsePoly::new();
+        p.add_term(2, 2);
+        p.add_term(5, 3);
+        p.add_term(0, 1);
+        p.add_term(3, 4);
+        p.add_term(1, 0);
+        assert_eq!(p.terms().map(|(e, c)| (e, *c)).collect::<Vec<_>>(), vec![(5, 3), (3, 4), (2, 2), (0, 1)]);
+
+        // 合并已有项，相消后删除
+        p.add_term(2, 5);
+        assert_eq!(p.coeff(2), 7);
+        p.add_term(3, -4);
+        assert_eq!(p.terms().map(|(e, c)| (e, *c)).collect::<Vec<_>>(), vec![(5, 3), (2, 7), (0, 1)]);
+    }
+
+    #[test]
+    fn test_display() {
+        let p = SparsePoly::from_terms([(3, -1), (2, 1), (1, -3), (0, -1)]);
+        assert_eq!(p.to_string(), "-x^3 + x^2 - 3x - 1");
+        assert_eq!(format!("{:?}", p), "[(3, -1), (2, 1), (1, -3), (0, -1)]");
+
+        assert_eq!(SparsePoly::from_terms([(0, 1)]).to_string(), "1");
+        assert_eq!(SparsePoly::from_terms([(1, -1.5)]).to_string(), "-1.5x");
+    }
+
+    #[test]
+    fn test_add() {
+        let p = SparsePoly::from_terms([(3, 1), (1, 2), (0, 5)]);
+        let q = SparsePoly::from_terms([(4, 2), (1, -2), (0, 1)]);
+
+        // x 项相消
+        let sum = &p + &q;
+        assert_eq!(sum.to_string(), "2x^4 + x^3 + 6");
+        assert_eq!(sum.len(), 3);
+
+        let zero = SparsePoly::new();
+        assert_eq!(&p + &zero, p);
+    }
+
+    #[test]
+    fn test_mul() {
+        // (x + 1)(x - 1) = x^2 - 1
+        let p = SparsePoly::from_terms([(1, 1), (0, 1)]);
+        let q = SparsePoly::from_terms([(1, 1), (0, -1)]);
+        let prod = &p * &q;
+        assert_eq!(prod, SparsePoly::from_terms([(2, 1), (0, -1)]));
+        assert_eq!(prod.to_string(), "x^2 - 1");
+
+        // (x + 1)^2 = x^2 + 2x + 1
+        assert_eq!((&p * &p).to_string(), "x^2 + 2x + 1");
+
+        assert!((&p * &SparsePoly::new()).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "exponent overflow")]
+    fn test_mul_overflow() {
+        // (x^(2^31) + 1)^2 的最高次数超出 u32
+        let p = SparsePoly::from_terms([(1 << 31, 1), (0, 1)]);
+        let _ = &p * &p;
+    }
+
+    #[test]
+    fn test_evaluate() {
+        // 3x^5 + 2x^2 + 1
+        let p = SparsePoly::from_terms([(5, 3), (2, 2), (0, 1)]);
+        assert_eq!(p.evaluate(0), 1);
+        assert_eq!(p.evaluate(1), 6);
+        assert_eq!(p.evaluate(2), 3 * 32 + 2 * 4 + 1);
+
+        // 没有常数项
+        let q = SparsePoly::from_terms([(7, 1.0), (3, -2.0)]);
+        assert_eq!(q.evaluate(2.0), 128.0 - 16.0);
+    }
+}
@@ -91,6 +91,16 @@ impl<T> TwoLockQueue<T> {
     pub fn len(&self) -> usize {
         self.len.load(Ordering::Acquire)
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for TwoLockQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -98,7 +108,6 @@ mod tests {
     use std::sync::Arc;
     use std::thread;
     use std::time::Duration;
-    use rand::Rng;
     use super::*;
 
     #[test]
@@ -165,7 +174,7 @@ mod tests {
             let queue = queue.clone();
             let counter = counter.clone();
             handles.push(thread::spawn(move || {
-                while let Some(_) = queue.pop() {
+                while queue.pop().is_some() {
                     counter.fetch_add(1, Ordering::SeqCst);
                 }
             }));
@@ -202,11 +211,11 @@ mod tests {
             let total_items = total_items.clone();
             handles.push(thread::spawn(move || {
                 loop {
-                    if let Some(_) = queue.pop() {
+                    if queue.pop().is_some() {
                         total_items.fetch_add(1, Ordering::SeqCst);
-                    } else if queue.len() == 0 {
+                    } else if queue.is_empty() {
                         thread::sleep(Duration::from_millis(1));
-                        if queue.len() == 0 {
+                        if queue.is_empty() {
                             break;
                         }
                     }